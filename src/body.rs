@@ -3,6 +3,7 @@ use async_std::io::{self, Cursor};
 use serde::{de::DeserializeOwned, Serialize};
 
 use std::fmt::{self, Debug};
+use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -55,10 +56,28 @@ pin_project_lite::pin_project! {
         #[pin]
         reader: Box<dyn BufRead + Unpin + Send + Sync + 'static>,
         mime: Mime,
-        length: Option<usize>,
+        length: BodySize,
     }
 }
 
+/// A hint about the size of a [`Body`], used by HTTP implementations to decide how to frame the
+/// message.
+///
+/// Modeled after actix-web's `BodySize`. Unlike a plain `Option<usize>`, this distinguishes a
+/// body that's semantically absent (a response to a `HEAD` request, or a `204 No Content`) from
+/// one that's merely empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodySize {
+    /// The body has no semantic size; omit the `Content-Length` header entirely.
+    None,
+    /// The body is empty; send `Content-Length: 0`.
+    Empty,
+    /// The body has a known size, in bytes.
+    Sized(u64),
+    /// The body's size isn't known ahead of time; use chunked `Transfer-Encoding`.
+    Stream,
+}
+
 impl Body {
     /// Create a new empty `Body`.
     ///
@@ -77,7 +96,30 @@ impl Body {
         Self {
             reader: Box::new(io::empty()),
             mime: mime::BYTE_STREAM,
-            length: Some(0),
+            length: BodySize::Empty,
+        }
+    }
+
+    /// Create a `Body` with no semantic content.
+    ///
+    /// Unlike [`Body::empty`], which represents a body that is present but has zero length (and
+    /// is sent with `Content-Length: 0`), this represents a body that is not present at all —
+    /// for example a response to a `HEAD` request, or a `204 No Content` response — where HTTP
+    /// implementations should omit the `Content-Length` header entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Body, BodySize};
+    ///
+    /// let body = Body::none();
+    /// assert_eq!(body.size(), BodySize::None);
+    /// ```
+    pub fn none() -> Self {
+        Self {
+            reader: Box::new(io::empty()),
+            mime: mime::BYTE_STREAM,
+            length: BodySize::None,
         }
     }
 
@@ -107,7 +149,10 @@ impl Body {
         Self {
             reader: Box::new(reader),
             mime: mime::BYTE_STREAM,
-            length: len,
+            length: match len {
+                Some(len) => BodySize::Sized(len as u64),
+                None => BodySize::Stream,
+            },
         }
     }
 
@@ -132,7 +177,7 @@ impl Body {
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
         Self {
             mime: mime::BYTE_STREAM,
-            length: Some(bytes.len()),
+            length: BodySize::Sized(bytes.len() as u64),
             reader: Box::new(io::Cursor::new(bytes)),
         }
     }
@@ -175,13 +220,31 @@ impl Body {
     pub fn from_json(json: impl Serialize) -> crate::Result<Self> {
         let bytes = serde_json::to_vec(&json)?;
         let body = Self {
-            length: Some(bytes.len()),
+            length: BodySize::Sized(bytes.len() as u64),
             reader: Box::new(Cursor::new(bytes)),
             mime: mime::JSON,
         };
         Ok(body)
     }
 
+    /// Get the size hint for this body, used by HTTP implementations to decide how to frame the
+    /// message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Body, BodySize};
+    /// use async_std::io::Cursor;
+    ///
+    /// let cursor = Cursor::new("Hello Nori");
+    /// let len = 10;
+    /// let body = Body::from_reader(cursor, Some(len));
+    /// assert_eq!(body.size(), BodySize::Sized(10));
+    /// ```
+    pub fn size(&self) -> BodySize {
+        self.length
+    }
+
     /// Get the length of the body in bytes.
     ///
     /// # Examples
@@ -196,12 +259,20 @@ impl Body {
     /// assert_eq!(body.len(), Some(10));
     /// ```
     pub fn len(&self) -> Option<usize> {
-        self.length
+        match self.length {
+            BodySize::Sized(len) => Some(len as usize),
+            BodySize::Empty => Some(0),
+            BodySize::None | BodySize::Stream => None,
+        }
     }
 
     /// Returns `true` if the body has a length of zero, and `false` otherwise.
     pub fn is_empty(&self) -> Option<bool> {
-        self.length.map(|length| length == 0)
+        match self.length {
+            BodySize::Empty => Some(true),
+            BodySize::Sized(len) => Some(len == 0),
+            BodySize::None | BodySize::Stream => None,
+        }
     }
 
     /// Get the inner reader from the `Body`
@@ -268,9 +339,302 @@ impl Body {
         Ok(serde_json::from_slice(&buf).map_err(io::Error::from)?)
     }
 
+    /// Creates a `Body` from a type, serializing it as an `application/x-www-form-urlencoded`
+    /// form.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/x-www-form-urlencoded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Body;
+    /// use http_types::convert::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Cat { name: String }
+    ///
+    /// let cat = Cat { name: String::from("chashu") };
+    /// let body = Body::from_form(cat);
+    /// # drop(body);
+    /// ```
+    pub fn from_form(form: impl Serialize) -> crate::Result<Self> {
+        let query = serde_urlencoded::to_string(form)?;
+        let bytes = query.into_bytes();
+        let body = Self {
+            length: BodySize::Sized(bytes.len() as u64),
+            reader: Box::new(Cursor::new(bytes)),
+            mime: mime::FORM,
+        };
+        Ok(body)
+    }
+
+    /// Parse the body as an `application/x-www-form-urlencoded` form, deserializing it to a
+    /// struct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), http_types::Error> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    /// use http_types::convert::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Cat { name: String }
+    ///
+    /// let cat = Cat { name: String::from("chashu") };
+    /// let body = Body::from_form(cat)?;
+    ///
+    /// let cat: Cat = body.into_form().await?;
+    /// assert_eq!(&cat.name, "chashu");
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn into_form<T: DeserializeOwned>(mut self) -> crate::Result<T> {
+        let mut buf = Vec::with_capacity(1024);
+        self.read_to_end(&mut buf).await?;
+        Ok(serde_urlencoded::from_bytes(&buf)?)
+    }
+
+    /// Cap the number of bytes that can be read from this body.
+    ///
+    /// At most `limit` bytes are read from the body. If the body holds more than that, the read
+    /// that reaches past the limit (including from [`into_bytes`], [`into_string`], and
+    /// [`into_json`]) fails with a [`BodyExceedsLimit`] error instead of reading an unbounded
+    /// amount of data from the peer; a body of exactly `limit` bytes reads through fine.
+    ///
+    /// [`into_bytes`]: #method.into_bytes
+    /// [`into_string`]: #method.into_string
+    /// [`into_json`]: #method.into_json
+    /// [`BodyExceedsLimit`]: struct.BodyExceedsLimit.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), http_types::Error> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// // A body that fits exactly within the limit reads through fine.
+    /// let body = Body::from_bytes(vec![1, 2, 3]).take(3);
+    /// assert_eq!(body.into_bytes().await?, vec![1, 2, 3]);
+    ///
+    /// // A body that exceeds the limit errors instead of over-reading.
+    /// let body = Body::from_bytes(vec![1, 2, 3]).take(2);
+    /// assert!(body.into_bytes().await.is_err());
+    /// # Ok(()) }) }
+    /// ```
+    pub fn take(mut self, limit: u64) -> Self {
+        self.length = match self.length {
+            BodySize::Sized(len) if len > limit => BodySize::Sized(limit),
+            other => other,
+        };
+        self.reader = Box::new(Take {
+            reader: self.reader,
+            limit,
+            read: 0,
+        });
+        self
+    }
+
     pub(crate) fn mime(&self) -> &Mime {
         &self.mime
     }
+
+    /// Create a streaming `multipart/form-data` body.
+    ///
+    /// Returns a [`MultipartForm`] builder. Fields are appended one at a time via
+    /// [`MultipartForm::text`] and [`MultipartForm::part`], and the parts are streamed out lazily
+    /// rather than buffered, so large file parts don't need to be held in memory all at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Body;
+    ///
+    /// let form = Body::from_multipart()
+    ///     .text("name", "chashu")
+    ///     .part("avatar", Body::from_bytes(vec![1, 2, 3]), Some("avatar.bin"), None);
+    /// let body: Body = form.into();
+    /// # drop(body);
+    /// ```
+    pub fn from_multipart() -> MultipartForm {
+        MultipartForm::new()
+    }
+
+    /// Create a `Body` from a file.
+    ///
+    /// The Mime type is inferred from the file extension if possible, falling back to
+    /// `application/octet-stream` if the extension is unknown or missing. The length is set from
+    /// the file's metadata, and the file is read in a streaming fashion rather than being loaded
+    /// into memory up front.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), http_types::Error> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from_file("./README.md").await?;
+    /// # drop(body);
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = async_std::fs::File::open(path).await?;
+        let len = file.metadata().await?.len();
+
+        Ok(Self {
+            length: BodySize::Sized(len),
+            reader: Box::new(io::BufReader::new(file)),
+            mime: mime_from_path(path).unwrap_or(mime::BYTE_STREAM),
+        })
+    }
+}
+
+/// Generate a boundary token for a `multipart/form-data` body.
+///
+/// `RandomState` only reads fresh OS randomness once per thread, lazily, on its first use; every
+/// subsequent `RandomState::new()` call on that thread reuses the same keys with one half
+/// incremented by a counter, so `high`/`low` are `SipHash(empty)` under keys that are unpredictable
+/// the first time a thread calls this and only counter-distinct after that. That's still enough to
+/// keep a boundary from colliding with arbitrary part content without pulling in an extra
+/// dependency just for this — it isn't a CSPRNG, so don't lean on it for anything that needs actual
+/// unpredictability.
+fn random_boundary() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let high = RandomState::new().build_hasher().finish();
+    let low = RandomState::new().build_hasher().finish();
+    format!("{:016x}{:016x}", high, low)
+}
+
+/// Escape a `name`/`filename` value for use inside a quoted-string `Content-Disposition`
+/// parameter, per RFC 7578's `quoted-string` rules: backslash and double-quote are
+/// backslash-escaped, and any CR/LF is stripped so the value can't inject extra header lines.
+fn quote_disposition_param(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\r' | '\n' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn mime_from_path(path: &Path) -> Option<Mime> {
+    match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "html" | "htm" => Some(mime::HTML),
+        "json" => Some(mime::JSON),
+        "txt" => Some(mime::PLAIN),
+        "xml" => Some(mime::XML),
+        _ => None,
+    }
+}
+
+/// A streaming builder for `multipart/form-data` bodies.
+///
+/// Constructed with [`Body::from_multipart`]. Convert the finished form into a [`Body`] with
+/// `.into()`, or call [`MultipartForm::finish`] directly.
+pub struct MultipartForm {
+    boundary: String,
+    reader: Box<dyn BufRead + Unpin + Send + Sync + 'static>,
+    length: Option<u64>,
+}
+
+impl MultipartForm {
+    fn new() -> Self {
+        Self {
+            boundary: random_boundary(),
+            reader: Box::new(io::empty()),
+            length: Some(0),
+        }
+    }
+
+    /// Append a text field.
+    pub fn text(mut self, name: impl AsRef<str>, value: impl Into<String>) -> Self {
+        let mut part = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n",
+            boundary = self.boundary,
+            name = quote_disposition_param(name.as_ref()),
+        );
+        part.push_str(&value.into());
+        part.push_str("\r\n");
+
+        self.length = self.length.map(|len| len + part.len() as u64);
+        self.reader = Box::new(self.reader.chain(Cursor::new(part.into_bytes())));
+        self
+    }
+
+    /// Append a file or stream field, with an optional filename and mime type.
+    pub fn part(
+        mut self,
+        name: impl AsRef<str>,
+        body: impl Into<Body>,
+        filename: Option<&str>,
+        mime: Option<Mime>,
+    ) -> Self {
+        let body = body.into();
+
+        let mut header = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"",
+            boundary = self.boundary,
+            name = quote_disposition_param(name.as_ref()),
+        );
+        if let Some(filename) = filename {
+            header.push_str(&format!(
+                "; filename=\"{}\"",
+                quote_disposition_param(filename.as_ref())
+            ));
+        }
+        header.push_str("\r\n");
+        if let Some(mime) = mime {
+            header.push_str(&format!("Content-Type: {}\r\n", mime));
+        }
+        header.push_str("\r\n");
+
+        self.length = match (self.length, body.len()) {
+            (Some(total), Some(body_len)) => {
+                Some(total + header.len() as u64 + body_len as u64 + 2)
+            }
+            _ => None,
+        };
+
+        self.reader = Box::new(
+            self.reader
+                .chain(Cursor::new(header.into_bytes()))
+                .chain(body.reader)
+                .chain(Cursor::new(b"\r\n".to_vec())),
+        );
+        self
+    }
+
+    /// Finish building the form, producing a `Body`.
+    pub fn finish(mut self) -> Body {
+        let trailer = format!("--{}--\r\n", self.boundary);
+        self.length = self.length.map(|len| len + trailer.len() as u64);
+        self.reader = Box::new(self.reader.chain(Cursor::new(trailer.into_bytes())));
+
+        Body {
+            reader: self.reader,
+            length: match self.length {
+                Some(len) => BodySize::Sized(len),
+                None => BodySize::Stream,
+            },
+            mime: format!("multipart/form-data; boundary={}", self.boundary)
+                .parse()
+                .expect("generated multipart mime should always be valid"),
+        }
+    }
+}
+
+impl From<MultipartForm> for Body {
+    fn from(form: MultipartForm) -> Self {
+        form.finish()
+    }
 }
 
 impl Debug for Body {
@@ -285,7 +649,7 @@ impl Debug for Body {
 impl From<String> for Body {
     fn from(s: String) -> Self {
         Self {
-            length: Some(s.len()),
+            length: BodySize::Sized(s.len() as u64),
             reader: Box::new(Cursor::new(s.into_bytes())),
             mime: mime::PLAIN,
         }
@@ -295,7 +659,7 @@ impl From<String> for Body {
 impl<'a> From<&'a str> for Body {
     fn from(s: &'a str) -> Self {
         Self {
-            length: Some(s.len()),
+            length: BodySize::Sized(s.len() as u64),
             reader: Box::new(Cursor::new(s.to_owned().into_bytes())),
             mime: mime::PLAIN,
         }
@@ -305,7 +669,7 @@ impl<'a> From<&'a str> for Body {
 impl From<Vec<u8>> for Body {
     fn from(b: Vec<u8>) -> Self {
         Self {
-            length: Some(b.len()),
+            length: BodySize::Sized(b.len() as u64),
             reader: Box::new(Cursor::new(b)),
             mime: mime::BYTE_STREAM,
         }
@@ -315,13 +679,109 @@ impl From<Vec<u8>> for Body {
 impl<'a> From<&'a [u8]> for Body {
     fn from(b: &'a [u8]) -> Self {
         Self {
-            length: Some(b.len()),
+            length: BodySize::Sized(b.len() as u64),
             reader: Box::new(io::Cursor::new(b.to_owned())),
             mime: mime::BYTE_STREAM,
         }
     }
 }
 
+/// The error returned when a [`Body`] wrapped with [`Body::take`] is read past its limit.
+#[derive(Debug)]
+pub struct BodyExceedsLimit {
+    limit: u64,
+}
+
+impl fmt::Display for BodyExceedsLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "body exceeded the configured limit of {} bytes", self.limit)
+    }
+}
+
+impl std::error::Error for BodyExceedsLimit {}
+
+/// A `BufRead` adapter that hands out at most `limit` bytes and errors if the underlying reader
+/// has more than that, used by [`Body::take`].
+struct Take {
+    reader: Box<dyn BufRead + Unpin + Send + Sync + 'static>,
+    limit: u64,
+    read: u64,
+}
+
+impl Read for Take {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.read >= self.limit {
+            // We've already handed out exactly `limit` bytes. Probe the underlying reader for
+            // one more: EOF here means the body was exactly `limit` bytes long (not an error);
+            // anything else means it's longer than the limit allows. Peek via `poll_fill_buf`
+            // rather than `poll_read` so the probe byte stays in the underlying reader's buffer
+            // instead of being consumed and discarded out from under whatever reads it next.
+            let this = self.get_mut();
+            return match Pin::new(&mut this.reader).poll_fill_buf(cx) {
+                Poll::Ready(Ok(buf)) if buf.is_empty() => Poll::Ready(Ok(0)),
+                Poll::Ready(Ok(_)) => {
+                    let limit = this.limit;
+                    Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        BodyExceedsLimit { limit },
+                    )))
+                }
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+
+        let max = ((self.limit - self.read) as usize).min(buf.len());
+        match Pin::new(&mut self.reader).poll_read(cx, &mut buf[..max]) {
+            Poll::Ready(Ok(n)) => {
+                self.read += n as u64;
+                Poll::Ready(Ok(n))
+            }
+            other => other,
+        }
+    }
+}
+
+impl BufRead for Take {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
+        let this = self.get_mut();
+        if this.read >= this.limit {
+            // Same EOF-vs-more-data probe as `poll_read`, just via the buffered path.
+            return match Pin::new(&mut this.reader).poll_fill_buf(cx) {
+                Poll::Ready(Ok(buf)) if buf.is_empty() => Poll::Ready(Ok(&[])),
+                Poll::Ready(Ok(_)) => {
+                    let limit = this.limit;
+                    Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        BodyExceedsLimit { limit },
+                    )))
+                }
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+
+        // Only expose what's left of the limit, so buffered readers can't consume past it.
+        let max = (this.limit - this.read) as usize;
+        match Pin::new(&mut this.reader).poll_fill_buf(cx) {
+            Poll::Ready(Ok(buf)) => {
+                let n = buf.len().min(max);
+                Poll::Ready(Ok(&buf[..n]))
+            }
+            other => other,
+        }
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.read += amt as u64;
+        Pin::new(&mut self.reader).consume(amt)
+    }
+}
+
 impl Read for Body {
     #[allow(missing_doc_code_examples)]
     fn poll_read(
@@ -344,3 +804,216 @@ impl BufRead for Body {
         Pin::new(&mut self.reader).consume(amt)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The boundary is randomly generated per form, so pull the one the form actually picked
+    // out of its `Content-Type` rather than hard-coding it.
+    fn boundary_of(body: &Body) -> String {
+        let mime = body.mime().to_string();
+        mime.split("boundary=")
+            .nth(1)
+            .expect("multipart body should carry a boundary")
+            .to_string()
+    }
+
+    #[async_std::test]
+    async fn multipart_wire_format() -> crate::Result<()> {
+        let form = Body::from_multipart().text("name", "chashu").part(
+            "avatar",
+            Body::from_bytes(vec![1, 2, 3]),
+            Some("avatar.bin"),
+            Some(mime::BYTE_STREAM),
+        );
+        let body: Body = form.into();
+        let boundary = boundary_of(&body);
+        let bytes = body.into_bytes().await?;
+
+        let mut expected = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"name\"\r\n\
+             \r\n\
+             chashu\r\n\
+             --{boundary}\r\n\
+             Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.bin\"\r\n\
+             Content-Type: application/octet-stream\r\n\
+             \r\n",
+            boundary = boundary,
+        )
+        .into_bytes();
+        expected.extend_from_slice(&[1, 2, 3]);
+        expected.extend_from_slice(b"\r\n");
+        expected.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        assert_eq!(bytes, expected);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn multipart_part_without_filename() -> crate::Result<()> {
+        let form = Body::from_multipart().part("file", Body::from_bytes(vec![9]), None, None);
+        let body: Body = form.into();
+        let boundary = boundary_of(&body);
+        let bytes = body.into_bytes().await?;
+
+        let mut expected = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"file\"\r\n\
+             \r\n",
+            boundary = boundary,
+        )
+        .into_bytes();
+        expected.push(9);
+        expected.extend_from_slice(b"\r\n");
+        expected.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        assert_eq!(bytes, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn quote_disposition_param_escapes_and_strips() {
+        assert_eq!(quote_disposition_param("plain"), "plain");
+        assert_eq!(
+            quote_disposition_param(r#"a "quoted" \name"#),
+            r#"a \"quoted\" \\name"#,
+        );
+        assert_eq!(
+            quote_disposition_param("line1\r\nContent-Type: text/evil\r\n\r\nline2"),
+            "line1Content-Type: text/evilline2",
+        );
+    }
+
+    #[test]
+    fn mime_from_path_matches_known_extensions_case_insensitively() {
+        assert_eq!(
+            mime_from_path(Path::new("report.JSON")).map(|m| m.to_string()),
+            Some(mime::JSON.to_string()),
+        );
+        assert_eq!(
+            mime_from_path(Path::new("page.Html")).map(|m| m.to_string()),
+            Some(mime::HTML.to_string()),
+        );
+        assert_eq!(mime_from_path(Path::new("photo.png")), None);
+        assert_eq!(mime_from_path(Path::new("no_extension")), None);
+    }
+
+    #[async_std::test]
+    async fn from_file_infers_mime_and_length() -> crate::Result<()> {
+        let path = std::env::temp_dir().join("http-types-body-test-from-file.JSON");
+        async_std::fs::write(&path, b"{}").await?;
+
+        let body = Body::from_file(&path).await?;
+        async_std::fs::remove_file(&path).await?;
+
+        assert_eq!(body.mime().to_string(), mime::JSON.to_string());
+        assert_eq!(body.len(), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn body_size_none_and_stream_have_no_length() {
+        let none = Body::none();
+        assert_eq!(none.size(), BodySize::None);
+        assert_eq!(none.len(), None);
+        assert_eq!(none.is_empty(), None);
+
+        let stream = Body::from_reader(Cursor::new(b"hello".to_vec()), None);
+        assert_eq!(stream.size(), BodySize::Stream);
+        assert_eq!(stream.len(), None);
+        assert_eq!(stream.is_empty(), None);
+    }
+
+    #[test]
+    fn body_size_empty_and_sized_report_their_length() {
+        let empty = Body::empty();
+        assert_eq!(empty.size(), BodySize::Empty);
+        assert_eq!(empty.len(), Some(0));
+        assert_eq!(empty.is_empty(), Some(true));
+
+        let sized = Body::from_bytes(vec![1, 2, 3]);
+        assert_eq!(sized.size(), BodySize::Sized(3));
+        assert_eq!(sized.len(), Some(3));
+        assert_eq!(sized.is_empty(), Some(false));
+    }
+
+    #[async_std::test]
+    async fn take_at_exact_limit_reads_fully() -> crate::Result<()> {
+        let body = Body::from_bytes(vec![1, 2, 3]).take(3);
+        assert_eq!(body.into_bytes().await?, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    // A reader that records how many bytes were actually pulled out of it via `poll_read`/
+    // `consume`, so we can tell a non-destructive EOF-vs-more-data probe apart from one that
+    // silently eats a byte off the underlying stream.
+    struct CountingReader {
+        inner: Cursor<Vec<u8>>,
+        consumed: std::sync::Arc<std::sync::atomic::AtomicU64>,
+    }
+
+    impl Read for CountingReader {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.get_mut();
+            match Pin::new(&mut this.inner).poll_read(cx, buf) {
+                Poll::Ready(Ok(n)) => {
+                    this.consumed
+                        .fetch_add(n as u64, std::sync::atomic::Ordering::SeqCst);
+                    Poll::Ready(Ok(n))
+                }
+                other => other,
+            }
+        }
+    }
+
+    impl BufRead for CountingReader {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            let this = self.get_mut();
+            Pin::new(&mut this.inner).poll_fill_buf(cx)
+        }
+
+        fn consume(mut self: Pin<&mut Self>, amt: usize) {
+            self.consumed
+                .fetch_add(amt as u64, std::sync::atomic::Ordering::SeqCst);
+            Pin::new(&mut self.inner).consume(amt)
+        }
+    }
+
+    #[async_std::test]
+    async fn take_over_limit_does_not_consume_past_the_limit() {
+        let consumed = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let reader = CountingReader {
+            inner: Cursor::new(vec![1, 2, 3, 4, 5]),
+            consumed: consumed.clone(),
+        };
+        let body = Body::from_reader(reader, Some(5)).take(3);
+
+        assert!(body.into_bytes().await.is_err());
+        // Only the 3 bytes `take` allows through should ever have left the underlying reader —
+        // the EOF-vs-more-data probe must peek, not consume.
+        assert_eq!(consumed.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[async_std::test]
+    async fn from_form_round_trips_through_into_form() -> crate::Result<()> {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Cat {
+            name: String,
+        }
+
+        let cat = Cat {
+            name: "chashu".into(),
+        };
+        let body = Body::from_form(&cat)?;
+        let round_tripped: Cat = body.into_form().await?;
+
+        assert_eq!(round_tripped, cat);
+        Ok(())
+    }
+}
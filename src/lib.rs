@@ -0,0 +1,3 @@
+mod body;
+
+pub use body::{Body, BodyExceedsLimit, BodySize, MultipartForm};